@@ -1,41 +1,295 @@
 use super::GitError;
 use regex::Regex;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::{fmt, fmt::{Display, Formatter}, result::Result as stdResult};
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Deserializer, de};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 pub type Result<A> = stdResult<A, GitError>;
 
+/// The transport a [`GitUrl`] was written in.
+///
+/// `ScpLike` is the `git@host:path` shorthand ssh accepts; unlike `Ssh` it
+/// has no room for a port segment (mirrors the distinction gix-url draws
+/// between the two ssh spellings).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitUrlScheme {
+    Git,
+    Ssh,
+    Http,
+    Https,
+    ScpLike,
+}
+
 #[derive(Debug)]
 pub struct GitUrl {
     pub(crate) value: String,
+    scheme: GitUrlScheme,
+    user: Option<String>,
+    host: String,
+    port: Option<String>,
+    path: String,
+    fragment: Option<String>,
+}
+
+impl GitUrl {
+    /// The transport this URL was written in.
+    pub fn scheme(&self) -> GitUrlScheme {
+        self.scheme
+    }
+
+    /// The user segment, if the URL carried one (e.g. the `git` in
+    /// `git@host:path` or the `user` in `ssh://user@host/path`).
+    pub fn user(&self) -> Option<&str> {
+        self.user.as_deref()
+    }
+
+    /// The host/authority segment.
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// The port segment, present when the URL's authority carried one —
+    /// any of the `git://`/`ssh://`/`http://`/`https://` forms may (the
+    /// scp-like `git@host:path` alias never does, since its `:` introduces
+    /// the path instead).
+    ///
+    /// Kept as a string rather than a number: this crate's own test suite
+    /// uses non-numeric port placeholders, so we surface whatever was
+    /// written instead of rejecting it here.
+    pub fn port(&self) -> Option<&str> {
+        self.port.as_deref()
+    }
+
+    /// The path segment, including the trailing `.git` and any trailing
+    /// slash.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The `#fragment` ref, if any (commonly a branch, tag, or commit-ish).
+    pub fn fragment(&self) -> Option<&str> {
+        self.fragment.as_deref()
+    }
+
+    /// Parses a terse, CLI-friendly shorthand into a fully qualified
+    /// [`GitUrl`].
+    ///
+    /// Recognizes forge prefixes such as `gh:user/repo` and `gl:group/project`
+    /// (see [`FORGE_ALIASES`]), as well as a bare `user/repo`, which defaults
+    /// to GitHub. Anything else is handed to [`FromStr::from_str`] unchanged,
+    /// so a fully qualified URL passed here still works.
+    pub fn parse_shorthand(value: &str) -> Result<Self> {
+        if let Some((prefix, rest)) = value.split_once(':') {
+            if let Some((_, host)) = FORGE_ALIASES.iter().find(|(alias, _)| *alias == prefix) {
+                let slug = rest.strip_suffix(".git").unwrap_or(rest);
+                if slug.is_empty() {
+                    return Err(GitError::InvalidUrl);
+                }
+                return GitUrl::from_str(&format!("https://{}/{}.git", host, slug));
+            }
+        }
+
+        if is_bare_owner_repo(value) {
+            let slug = value.strip_suffix(".git").unwrap_or(value);
+            return GitUrl::from_str(&format!("https://github.com/{}.git", slug));
+        }
+
+        GitUrl::from_str(value)
+    }
+
+    /// Resolves a leading `~`/`~user` in this URL's path against a local
+    /// home directory, returning `None` if the path carries no tilde.
+    ///
+    /// `current_user` names the user a bare `~` should resolve to; a named
+    /// `~user` segment resolves to that user's home instead, computed as a
+    /// sibling of `current_user`'s home directory. The raw URL (and its
+    /// `Display` form) are left untouched by this — remote transports
+    /// where `~` means "the remote's home", not a local path, should simply
+    /// not call this method.
+    pub fn expand_path(&self, current_user: Option<&str>) -> Option<PathBuf> {
+        let path = self.path();
+
+        if let Some(rest) = path.strip_prefix("~/") {
+            return home_dir(current_user?).map(|home| home.join(rest));
+        }
+        if path == "~" {
+            return home_dir(current_user?);
+        }
+        if let Some(rest) = path.strip_prefix('~') {
+            let (name, remainder) = rest.split_once('/').unwrap_or((rest, ""));
+            return home_dir(current_user?)
+                .and_then(|home| named_home_dir(&home, name))
+                .map(|home| home.join(remainder));
+        }
+
+        None
+    }
+}
+
+/// The home directory of `current_user`, resolved from the process
+/// environment.
+///
+/// This crate has no access to a real user database, so it can only vouch
+/// for the user the process is actually running as: if `current_user`
+/// doesn't match the ambient `$USER`/`$LOGNAME`, there is no way to know
+/// their home directory, and this returns `None` rather than guessing
+/// (e.g. by assuming it's a sibling of the process's own home).
+fn home_dir(current_user: &str) -> Option<PathBuf> {
+    // Only refuse when we have ambient identity that actively disagrees;
+    // many minimal environments (containers, systemd services) set `$HOME`
+    // without `$USER`/`$LOGNAME`, and there's nothing to contradict there.
+    if let Ok(ambient_user) = std::env::var("USER").or_else(|_| std::env::var("LOGNAME")) {
+        if ambient_user != current_user {
+            return None;
+        }
+    }
+    std::env::var_os("HOME").map(PathBuf::from)
+}
+
+/// `name`'s home directory, computed as a sibling of `home` (i.e.
+/// `home`'s parent joined with `name`) — the same convention
+/// `git`/`ssh` use for resolving `~name` without a full user database.
+fn named_home_dir(home: &Path, name: &str) -> Option<PathBuf> {
+    home.parent().map(|parent| parent.join(name))
+}
+
+/// Forge prefixes recognized by [`GitUrl::parse_shorthand`], mapping the
+/// short alias to the host it expands to.
+const FORGE_ALIASES: &[(&str, &str)] = &[("gh", "github.com"), ("gl", "gitlab.com")];
+
+/// Whether `value` looks like a bare `owner/repo` shorthand rather than a
+/// URL, an scp-like alias, or a forge-prefixed shorthand.
+fn is_bare_owner_repo(value: &str) -> bool {
+    let mut parts = value.split('/');
+    let (owner, repo) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(owner), Some(repo), None) => (owner, repo),
+        _ => return false,
+    };
+    !owner.is_empty()
+        && !repo.is_empty()
+        && !owner.contains(':')
+        && !owner.contains('@')
+        && !repo.contains(':')
+        && !repo.contains('@')
 }
 
 impl FromStr for GitUrl {
     type Err = GitError;
 
     fn from_str(value: &str) -> Result<Self> {
-        //Regex from https://github.com/jonschlinkert/is-git-url
-        let re =
-            Regex::new("(?:git|ssh|https?|git@[-\\w.]+):(//)?(.*?)(\\.git)(/?|\\#[-\\d\\w._]+?)$")
-                .unwrap();
-        if re.is_match(value) {
-            Ok(GitUrl {
-                value: String::from(value),
-            })
-        } else {
-            Err(GitError::InvalidUrl)
+        // Adapted from https://github.com/jonschlinkert/is-git-url: the
+        // `(//)?` in the original regex let no-slash scheme forms like
+        // `git:path/to/repo.git` slip through even though `git`/`ssh`/`http`/
+        // `https` always require `//` in practice. Only the scp-like
+        // `git@host:path` alias has no `//`, so that's the one alternative
+        // that doesn't require it.
+        let re = Regex::new(
+            "(?:(?:git|ssh|https?)://|git@[-\\w.]+:)(.*?)(\\.git)(/?|\\#[-\\d\\w._]+?)$",
+        )
+        .unwrap();
+        if !re.is_match(value) {
+            return Err(GitError::InvalidUrl);
         }
+
+        let (scheme, user, host, port, rest) = if let Some(rest) = value.strip_prefix("git://") {
+            let (user, host, port, rest) = split_authority(rest);
+            (GitUrlScheme::Git, user, host, port, rest)
+        } else if let Some(rest) = value.strip_prefix("ssh://") {
+            let (user, host, port, rest) = split_authority(rest);
+            (GitUrlScheme::Ssh, user, host, port, rest)
+        } else if let Some(rest) = value.strip_prefix("https://") {
+            let (user, host, port, rest) = split_authority(rest);
+            (GitUrlScheme::Https, user, host, port, rest)
+        } else if let Some(rest) = value.strip_prefix("http://") {
+            let (user, host, port, rest) = split_authority(rest);
+            (GitUrlScheme::Http, user, host, port, rest)
+        } else {
+            // Only the scp-like alias form is left: `git@host:path`, the
+            // `git@[-\w.]+` branch of the validating regex above.
+            let rest = value.strip_prefix("git@").ok_or(GitError::InvalidUrl)?;
+            let (host, rest) = rest.split_once(':').ok_or(GitError::InvalidUrl)?;
+            (
+                GitUrlScheme::ScpLike,
+                Some("git".to_string()),
+                host.to_string(),
+                None,
+                rest.to_string(),
+            )
+        };
+
+        let (path, fragment) = match rest.split_once('#') {
+            Some((path, fragment)) => (path.to_string(), Some(fragment.to_string())),
+            None => (rest, None),
+        };
+
+        Ok(GitUrl {
+            value: String::from(value),
+            scheme,
+            user,
+            host,
+            port,
+            path,
+            fragment,
+        })
     }
 }
 
+/// Splits a `user@host:port` authority (the part between `scheme://` and the
+/// first unescaped `/`) into its pieces, returning the remainder of the URL
+/// (the path, possibly with a trailing fragment) as well.
+///
+/// Kept lenient on purpose: this crate's own test suite includes authorities
+/// with stray `@`/`:` characters inside the user segment (e.g. basic-auth
+/// style credentials), so the split is "last `@` wins, first `:` after that
+/// wins" rather than a strict grammar.
+fn split_authority(rest: &str) -> (Option<String>, String, Option<String>, String) {
+    let (authority, remainder) = match rest.split_once('/') {
+        Some((authority, remainder)) => (authority, remainder.to_string()),
+        None => (rest, String::new()),
+    };
+
+    let (user, host_port) = match authority.rfind('@') {
+        Some(idx) => (Some(authority[..idx].to_string()), &authority[idx + 1..]),
+        None => (None, authority),
+    };
+
+    let (host, port) = match host_port.split_once(':') {
+        Some((host, port)) => (host.to_string(), Some(port.to_string())),
+        None => (host_port.to_string(), None),
+    };
+
+    (user, host, port, remainder)
+}
+
 impl Display for GitUrl {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.value)
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for GitUrl {
+    fn serialize<S>(&self, serializer: S) -> stdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for GitUrl {
+    fn deserialize<D>(deserializer: D) -> stdResult<GitUrl, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        GitUrl::from_str(&s).map_err(de::Error::custom)
+    }
+}
+
 #[derive(Debug)]
 pub struct BranchName {
     pub(crate) value: String
@@ -43,8 +297,8 @@ pub struct BranchName {
 
 impl FromStr for BranchName {
     type Err = GitError;
-    fn from_str(s: &str) -> Result<Self> { 
-        if is_valid_reference_name(s) {
+    fn from_str(s: &str) -> Result<Self> {
+        if is_valid_reference_name(s, RefFormat::ALLOW_ONELEVEL) {
             Ok(BranchName {
                 value: String::from(s)
             })
@@ -52,7 +306,25 @@ impl FromStr for BranchName {
             Err(GitError::InvalidRefName)
         }
     }
-    
+
+}
+
+impl BranchName {
+    /// Collapses redundant slashes in `name` and validates the result,
+    /// returning the canonical form — matching libgit2's
+    /// `Reference::normalize_name`.
+    pub fn normalize(name: &str) -> Result<String> {
+        let normalized = name
+            .split('/')
+            .filter(|component| !component.is_empty())
+            .collect::<Vec<_>>()
+            .join("/");
+        if is_valid_reference_name(&normalized, RefFormat::ALLOW_ONELEVEL) {
+            Ok(normalized)
+        } else {
+            Err(GitError::InvalidRefName)
+        }
+    }
 }
 
 impl Display for BranchName {
@@ -61,6 +333,16 @@ impl Display for BranchName {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for BranchName {
+    fn serialize<S>(&self, serializer: S) -> stdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for BranchName {
     fn deserialize<D>(deserializer: D) -> stdResult<BranchName, D::Error>
@@ -72,20 +354,126 @@ impl<'de> Deserialize<'de> for BranchName {
     }
 }
 
-const INVALID_REFERENCE_CHARS: [char; 5] = [' ', '~', '^', ':', '\\'];
+/// A remote-tracking reference such as `origin/master` or `upstream/main`:
+/// a remote name paired with a [`BranchName`] on that remote.
+#[derive(Debug)]
+pub struct RemoteName {
+    pub(crate) remote: String,
+    pub(crate) branch: BranchName,
+}
+
+impl RemoteName {
+    /// Builds a `RemoteName` from an already-parsed remote and branch,
+    /// validating the remote half with the same reference-name rules
+    /// `BranchName` uses.
+    ///
+    /// The remote half may not itself contain a `/`: `RemoteName`'s
+    /// `Display`/`FromStr` split on the first `/`, so a slash in `remote`
+    /// would make the constructed value round-trip to a different one.
+    pub fn new(remote: &str, branch: BranchName) -> Result<Self> {
+        if remote.contains('/') || !is_valid_reference_name(remote, RefFormat::ALLOW_ONELEVEL) {
+            return Err(GitError::InvalidRefName);
+        }
+        Ok(RemoteName {
+            remote: String::from(remote),
+            branch,
+        })
+    }
+
+    /// The remote name, e.g. `origin`.
+    pub fn remote(&self) -> &str {
+        &self.remote
+    }
+
+    /// The branch on that remote.
+    pub fn branch(&self) -> &BranchName {
+        &self.branch
+    }
+}
+
+impl FromStr for RemoteName {
+    type Err = GitError;
+    fn from_str(s: &str) -> Result<Self> {
+        let (remote, branch) = s.split_once('/').ok_or(GitError::InvalidRefName)?;
+        RemoteName::new(remote, BranchName::from_str(branch)?)
+    }
+}
+
+impl Display for RemoteName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.remote, self.branch)
+    }
+}
+
+/// Flags controlling which reference-name rules [`is_valid_reference_name`]
+/// relaxes, mirroring `git check-ref-format --allow-onelevel` and
+/// `--refspec-pattern`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RefFormat(u8);
+
+impl RefFormat {
+    /// No relaxations: the strict, multi-level `git check-ref-format` rules.
+    pub const NONE: RefFormat = RefFormat(0);
+    /// Permit a name with no `/` in it at all (e.g. a plain branch name).
+    pub const ALLOW_ONELEVEL: RefFormat = RefFormat(1 << 0);
+    /// Permit a single `*` glob in place of one path component, for refspecs.
+    pub const REFSPEC_PATTERN: RefFormat = RefFormat(1 << 1);
+
+    fn contains(self, other: RefFormat) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for RefFormat {
+    type Output = RefFormat;
+    fn bitor(self, rhs: RefFormat) -> RefFormat {
+        RefFormat(self.0 | rhs.0)
+    }
+}
+
+const INVALID_REFERENCE_CHARS: [char; 7] = [' ', '~', '^', ':', '\\', '?', '['];
 const INVALID_REFERENCE_START: &str = "-";
 const INVALID_REFERENCE_END: &str = ".";
 
-fn is_valid_reference_name(name: &str) -> bool {
-    !name.starts_with(INVALID_REFERENCE_START)
-        && !name.ends_with(INVALID_REFERENCE_END)
-        && name.chars().all(|c| {
-            !c.is_ascii_control() && INVALID_REFERENCE_CHARS.iter().all(|invalid| &c != invalid)
-        })
-        && !name.contains("/.")
-        && !name.contains("@{")
-        && !name.contains("..")
-        && name != "@"
+/// Checks `name` against `git check-ref-format`'s rules, as relaxed by
+/// `format`.
+fn is_valid_reference_name(name: &str, format: RefFormat) -> bool {
+    if name.is_empty()
+        || name.starts_with(INVALID_REFERENCE_START)
+        || name.ends_with(INVALID_REFERENCE_END)
+        || name.starts_with('/')
+        || name.ends_with('/')
+        || name.contains("//")
+        || name.contains("..")
+        || name.contains("@{")
+        || name == "@"
+    {
+        return false;
+    }
+
+    let components: Vec<&str> = name.split('/').collect();
+    if components.len() < 2 && !format.contains(RefFormat::ALLOW_ONELEVEL) {
+        return false;
+    }
+    if components.iter().filter(|component| **component == "*").count() > 1 {
+        return false;
+    }
+
+    components.iter().all(|component| {
+        // A lone `*` stands in for a whole path component in a refspec
+        // pattern; anywhere else `*` is rejected like the other globs below.
+        if *component == "*" && format.contains(RefFormat::REFSPEC_PATTERN) {
+            return true;
+        }
+        !component.is_empty()
+            && !component.starts_with('.')
+            && !component.ends_with(".lock")
+            && component.chars().all(|c| {
+                c != '*'
+                    && !c.is_ascii_control()
+                    && INVALID_REFERENCE_CHARS.iter().all(|invalid| &c != invalid)
+            })
+    })
 }
 
 
@@ -132,7 +520,7 @@ mod tests {
             "ssh://user@host.xz:port/path/to/repo.git/",
         );
 
-        for url in valid_urls.iter() {  
+        for url in valid_urls.iter() {
             assert!(GitUrl::from_str(url).is_ok())
         }
     }
@@ -154,19 +542,147 @@ mod tests {
             "user@host.xz:/path/to/repo.git/",
             "user@host.xz:path/to/repo.git",
             "user@host.xz:~user/path/to/repo.git/",
-            "~/path/to/repo.git"
+            "~/path/to/repo.git",
+            "git:path/to/repo.git",
+            "ssh:path/to/repo.git",
+            "http:path/to/repo.git",
+            "https:path/to/repo.git"
         );
 
-        for url in invalid_urls.iter() {  
+        for url in invalid_urls.iter() {
             assert!(GitUrl::from_str(url).is_err())
         }
     }
 
+    #[test]
+    fn test_git_url_components() {
+        let url = GitUrl::from_str("ssh://user@host.xz:2222/path/to/repo.git#master").unwrap();
+        assert_eq!(url.scheme(), GitUrlScheme::Ssh);
+        assert_eq!(url.user(), Some("user"));
+        assert_eq!(url.host(), "host.xz");
+        assert_eq!(url.port(), Some("2222"));
+        assert_eq!(url.path(), "path/to/repo.git");
+        assert_eq!(url.fragment(), Some("master"));
+        assert_eq!(url.to_string(), "ssh://user@host.xz:2222/path/to/repo.git#master");
+    }
+
+    #[test]
+    fn test_git_url_port_also_parses_for_non_ssh_schemes() {
+        let git_url = GitUrl::from_str("git://host.xz:9418/path/to/repo.git").unwrap();
+        assert_eq!(git_url.port(), Some("9418"));
+
+        let https_url = GitUrl::from_str("https://host.xz:8443/path/to/repo.git").unwrap();
+        assert_eq!(https_url.port(), Some("8443"));
+    }
+
+    #[test]
+    fn test_scp_like_git_url_components() {
+        let url = GitUrl::from_str("git@github.com:user/project.git").unwrap();
+        assert_eq!(url.scheme(), GitUrlScheme::ScpLike);
+        assert_eq!(url.user(), Some("git"));
+        assert_eq!(url.host(), "github.com");
+        assert_eq!(url.port(), None);
+        assert_eq!(url.path(), "user/project.git");
+    }
+
+    #[test]
+    fn test_parse_shorthand_forge_aliases() {
+        assert_eq!(
+            GitUrl::parse_shorthand("gh:user/repo").unwrap().to_string(),
+            "https://github.com/user/repo.git"
+        );
+        assert_eq!(
+            GitUrl::parse_shorthand("gl:group/project").unwrap().to_string(),
+            "https://gitlab.com/group/project.git"
+        );
+    }
+
+    #[test]
+    fn test_parse_shorthand_bare_owner_repo() {
+        assert_eq!(
+            GitUrl::parse_shorthand("user/repo").unwrap().to_string(),
+            "https://github.com/user/repo.git"
+        );
+    }
+
+    #[test]
+    fn test_parse_shorthand_passes_through_full_urls() {
+        let url = GitUrl::parse_shorthand("https://github.com/user/repo.git").unwrap();
+        assert_eq!(url.to_string(), "https://github.com/user/repo.git");
+    }
+
+    #[test]
+    fn test_parse_shorthand_does_not_double_append_git_suffix() {
+        assert_eq!(
+            GitUrl::parse_shorthand("gh:user/repo.git").unwrap().to_string(),
+            "https://github.com/user/repo.git"
+        );
+        assert_eq!(
+            GitUrl::parse_shorthand("user/repo.git").unwrap().to_string(),
+            "https://github.com/user/repo.git"
+        );
+    }
+
+    #[test]
+    fn test_parse_shorthand_rejects_empty_slug_after_forge_prefix() {
+        assert!(GitUrl::parse_shorthand("gh:").is_err());
+        assert!(GitUrl::parse_shorthand("gh:.git").is_err());
+    }
+
+    #[test]
+    fn test_expand_path_current_user_home() {
+        let url = GitUrl::from_str("ssh://host.xz/~/path/to/repo.git").unwrap();
+        std::env::set_var("HOME", "/home/alice");
+        std::env::set_var("USER", "alice");
+        assert_eq!(
+            url.expand_path(Some("alice")),
+            Some(PathBuf::from("/home/alice/path/to/repo.git"))
+        );
+    }
+
+    #[test]
+    fn test_expand_path_named_user_home() {
+        let url = GitUrl::from_str("ssh://host.xz/~bob/path/to/repo.git").unwrap();
+        std::env::set_var("HOME", "/home/alice");
+        std::env::set_var("USER", "alice");
+        assert_eq!(
+            url.expand_path(Some("alice")),
+            Some(PathBuf::from("/home/bob/path/to/repo.git"))
+        );
+    }
+
+    #[test]
+    fn test_expand_path_no_tilde_is_none() {
+        let url = GitUrl::from_str("ssh://host.xz/path/to/repo.git").unwrap();
+        assert_eq!(url.expand_path(Some("alice")), None);
+    }
+
+    #[test]
+    fn test_expand_path_refuses_to_guess_unverifiable_user() {
+        let url = GitUrl::from_str("ssh://host.xz/~/path/to/repo.git").unwrap();
+        std::env::set_var("HOME", "/home/alice");
+        std::env::set_var("USER", "alice");
+        assert_eq!(url.expand_path(Some("mallory")), None);
+    }
+
+    #[test]
+    fn test_expand_path_trusts_home_without_ambient_identity() {
+        let url = GitUrl::from_str("ssh://host.xz/~/path/to/repo.git").unwrap();
+        std::env::remove_var("USER");
+        std::env::remove_var("LOGNAME");
+        std::env::set_var("HOME", "/home/alice");
+        assert_eq!(
+            url.expand_path(Some("alice")),
+            Some(PathBuf::from("/home/alice/path/to/repo.git"))
+        );
+        std::env::set_var("USER", "alice");
+    }
+
     #[test]
     fn test_valid_reference_names() {
         let valid_reference = "avalidreference";
 
-        assert!(is_valid_reference_name(valid_reference))
+        assert!(is_valid_reference_name(valid_reference, RefFormat::ALLOW_ONELEVEL))
     }
 
     #[test]
@@ -182,7 +698,118 @@ mod tests {
         );
 
         for reference_name in invalid_references.iter() {
-            assert!(!is_valid_reference_name(reference_name))
+            assert!(!is_valid_reference_name(reference_name, RefFormat::ALLOW_ONELEVEL))
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_check_ref_format_rejects_dot_components_and_lock_suffix() {
+        assert!(!is_valid_reference_name("refs/.hidden/branch", RefFormat::ALLOW_ONELEVEL));
+        assert!(!is_valid_reference_name("refs/heads/topic.lock", RefFormat::ALLOW_ONELEVEL));
+    }
+
+    #[test]
+    fn test_check_ref_format_rejects_slashes() {
+        assert!(!is_valid_reference_name("/refs/heads/topic", RefFormat::ALLOW_ONELEVEL));
+        assert!(!is_valid_reference_name("refs/heads/topic/", RefFormat::ALLOW_ONELEVEL));
+        assert!(!is_valid_reference_name("refs//heads/topic", RefFormat::ALLOW_ONELEVEL));
+    }
+
+    #[test]
+    fn test_check_ref_format_rejects_glob_chars_by_default() {
+        assert!(!is_valid_reference_name("refs/heads/*", RefFormat::ALLOW_ONELEVEL));
+        assert!(!is_valid_reference_name("refs/heads/topic?", RefFormat::ALLOW_ONELEVEL));
+        assert!(!is_valid_reference_name("refs/heads/to[pic", RefFormat::ALLOW_ONELEVEL));
+    }
+
+    #[test]
+    fn test_check_ref_format_refspec_pattern() {
+        let format = RefFormat::ALLOW_ONELEVEL | RefFormat::REFSPEC_PATTERN;
+        assert!(is_valid_reference_name("refs/heads/*", format));
+        assert!(!is_valid_reference_name("refs/heads/to*pic", format));
+    }
+
+    #[test]
+    fn test_check_ref_format_rejects_one_level_without_allow_onelevel() {
+        assert!(!is_valid_reference_name("master", RefFormat::NONE));
+        assert!(is_valid_reference_name("refs/heads/master", RefFormat::NONE));
+    }
+
+    #[test]
+    fn test_remote_name_parses_remote_and_branch() {
+        let remote = RemoteName::from_str("origin/master").unwrap();
+        assert_eq!(remote.remote(), "origin");
+        assert_eq!(remote.branch().to_string(), "master");
+        assert_eq!(remote.to_string(), "origin/master");
+    }
+
+    #[test]
+    fn test_remote_name_branch_may_contain_slashes() {
+        let remote = RemoteName::from_str("upstream/feature/foo").unwrap();
+        assert_eq!(remote.remote(), "upstream");
+        assert_eq!(remote.branch().to_string(), "feature/foo");
+    }
+
+    #[test]
+    fn test_remote_name_rejects_invalid_halves() {
+        assert!(RemoteName::from_str("no-branch").is_err());
+        assert!(RemoteName::from_str("-origin/master").is_err());
+        assert!(RemoteName::from_str("origin/.master").is_err());
+    }
+
+    #[test]
+    fn test_remote_name_new_from_parts() {
+        let branch = BranchName::from_str("main").unwrap();
+        let remote = RemoteName::new("origin", branch).unwrap();
+        assert_eq!(remote.to_string(), "origin/main");
+    }
+
+    #[test]
+    fn test_remote_name_new_rejects_slash_in_remote() {
+        let branch = BranchName::from_str("main").unwrap();
+        assert!(RemoteName::new("origin/foo", branch).is_err());
+    }
+
+    #[test]
+    fn test_remote_name_round_trips_through_display_and_from_str() {
+        let branch = BranchName::from_str("main").unwrap();
+        let remote = RemoteName::new("origin", branch).unwrap();
+        let round_tripped = RemoteName::from_str(&remote.to_string()).unwrap();
+        assert_eq!(remote.remote(), round_tripped.remote());
+        assert_eq!(remote.branch().to_string(), round_tripped.branch().to_string());
+    }
+
+    #[test]
+    fn test_branch_name_normalize_collapses_slashes() {
+        assert_eq!(
+            BranchName::normalize("refs//heads///master").unwrap(),
+            "refs/heads/master"
+        );
+        assert!(BranchName::normalize("refs/heads/@{upstream}").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_git_url_serde_round_trip() {
+        let url = GitUrl::from_str("https://github.com/user/repo.git").unwrap();
+        let serialized = serde_json::to_string(&url).unwrap();
+        let deserialized: GitUrl = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(url.to_string(), deserialized.to_string());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_git_url_deserialize_rejects_invalid_url() {
+        let result: stdResult<GitUrl, _> = serde_json::from_str("\"not a git url\"");
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_branch_name_serde_round_trip() {
+        let branch = BranchName::from_str("feature/foo").unwrap();
+        let serialized = serde_json::to_string(&branch).unwrap();
+        let deserialized: BranchName = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(branch.to_string(), deserialized.to_string());
+    }
+}